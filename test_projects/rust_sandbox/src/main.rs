@@ -1,6 +1,6 @@
 mod math;
 
-use math::{distance_squared, Point};
+use math::{distance_by, distance_squared, Metric, Point};
 
 fn greet(name: &str) -> String {
     format!("Hello, {name}!")
@@ -18,4 +18,23 @@ fn main() {
 
     origin.translate(1, 1);
     println!("moved origin = {:?}", origin);
+
+    let right = origin.with_x(origin.x + 10);
+    let up = origin.with_y(origin.y + 10);
+    let shifted = origin.translated(5, 5);
+    println!("with_x = {right:?}, with_y = {up:?}, translated = {shifted:?}");
+
+    let labeled = Point::new(origin.x, 'o').mixup(Point::new(0, origin.y));
+    println!("mixup = {labeled:?}");
+
+    let a = Point::new(0.0, 0.0);
+    let b = Point::new(3.0, 4.0);
+    println!("distance = {}", a.distance(&b));
+    println!("distance_from_origin = {}", b.distance_from_origin());
+    println!(
+        "euclidean = {}, manhattan = {}, chebyshev = {}",
+        distance_by(&a, &b, Metric::Euclidean),
+        distance_by(&a, &b, Metric::Manhattan),
+        distance_by(&a, &b, Metric::Chebyshev),
+    );
 }
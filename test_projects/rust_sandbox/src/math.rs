@@ -1,29 +1,96 @@
+use std::ops::{Add, Mul, Sub};
+
 #[derive(Debug, Clone, Copy)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
+pub struct Point<T, U = T> {
+    pub x: T,
+    pub y: U,
 }
 
-impl Point {
-    pub fn new(x: i32, y: i32) -> Self {
+impl<T, U> Point<T, U> {
+    pub fn new(x: T, y: U) -> Self {
         Self { x, y }
     }
 
-    pub fn translate(&mut self, dx: i32, dy: i32) {
-        self.x += dx;
-        self.y += dy;
+    /// Takes `x` from `self` and `y` from `other`, recombining two points
+    /// that may have different coordinate types into one new point.
+    pub fn mixup<V, W>(self, other: Point<V, W>) -> Point<T, W> {
+        Point {
+            x: self.x,
+            y: other.y,
+        }
+    }
+
+    /// Returns a new point with `x` replaced, leaving `self` untouched.
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, y: self.y }
+    }
+
+    /// Returns a new point with `y` replaced, leaving `self` untouched.
+    pub fn with_y(self, y: U) -> Self {
+        Self { x: self.x, y }
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    pub fn translate(&mut self, dx: T, dy: T) {
+        self.x = self.x + dx;
+        self.y = self.y + dy;
+    }
+
+    /// Non-mutating counterpart to [`translate`](Self::translate): returns a
+    /// translated copy instead of updating `self` in place.
+    pub fn translated(self, dx: T, dy: T) -> Self {
+        Self {
+            x: self.x + dx,
+            y: self.y + dy,
+        }
     }
 }
 
-pub fn distance_squared(a: &Point, b: &Point) -> i32 {
+pub fn distance_squared<T>(a: &Point<T>, b: &Point<T>) -> T
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
     let dx = a.x - b.x;
     let dy = a.y - b.y;
     dx * dx + dy * dy
 }
 
+impl Point<f64> {
+    pub fn distance(&self, other: &Self) -> f64 {
+        distance_squared(self, other).sqrt()
+    }
+
+    pub fn distance_from_origin(&self) -> f64 {
+        self.distance(&Point::new(0.0, 0.0))
+    }
+}
+
+/// A distance norm for comparing points, for callers (e.g. grid or
+/// pathfinding code) that need something other than Euclidean distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Euclidean,
+    Manhattan,
+    Chebyshev,
+}
+
+pub fn distance_by(a: &Point<f64>, b: &Point<f64>, metric: Metric) -> f64 {
+    let dx = (a.x - b.x).abs();
+    let dy = (a.y - b.y).abs();
+    match metric {
+        Metric::Euclidean => a.distance(b),
+        Metric::Manhattan => dx + dy,
+        Metric::Chebyshev => dx.max(dy),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{distance_squared, Point};
+    use super::{distance_by, distance_squared, Metric, Point};
 
     #[test]
     fn computes_distance_squared() {
@@ -31,4 +98,55 @@ mod tests {
         let b = Point::new(3, 4);
         assert_eq!(distance_squared(&a, &b), 25);
     }
+
+    #[test]
+    fn translate_moves_point() {
+        let mut origin: Point<i32> = Point::new(0, 0);
+        origin.translate(1, 1);
+        assert_eq!((origin.x, origin.y), (1, 1));
+    }
+
+    #[test]
+    fn mixup_combines_heterogeneous_points() {
+        let a = Point::new(1, 2.0);
+        let b = Point::new("hi", 'c');
+        let c = a.mixup(b);
+        assert_eq!((c.x, c.y), (1, 'c'));
+    }
+
+    #[test]
+    fn computes_euclidean_distance() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(3.0, 4.0);
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(b.distance_from_origin(), 5.0);
+    }
+
+    #[test]
+    fn distance_by_supports_alternate_metrics() {
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(3.0, 4.0);
+        assert_eq!(distance_by(&a, &b, Metric::Euclidean), 5.0);
+        assert_eq!(distance_by(&a, &b, Metric::Manhattan), 7.0);
+        assert_eq!(distance_by(&a, &b, Metric::Chebyshev), 4.0);
+    }
+
+    #[test]
+    fn with_x_and_with_y_return_new_points() {
+        let origin: Point<i32> = Point::new(0, 0);
+        let right = origin.with_x(5);
+        assert_eq!((right.x, right.y), (5, 0));
+        assert_eq!((origin.x, origin.y), (0, 0));
+
+        let up = origin.with_y(5);
+        assert_eq!((up.x, up.y), (0, 5));
+    }
+
+    #[test]
+    fn translated_returns_new_point_without_mutating() {
+        let origin: Point<i32> = Point::new(0, 0);
+        let right = origin.translated(10, 0);
+        assert_eq!((right.x, right.y), (10, 0));
+        assert_eq!((origin.x, origin.y), (0, 0));
+    }
 }